@@ -0,0 +1,127 @@
+use random::Source;
+
+use Distribution;
+
+/// A Bernoulli distribution.
+#[derive(Clone, Copy)]
+pub struct Bernoulli {
+    /// The probability of success.
+    pub p: f64,
+}
+
+impl Bernoulli {
+    /// Create a Bernoulli distribution with success probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in `[0, 1]`.
+    #[inline]
+    pub fn new(p: f64) -> Bernoulli {
+        debug_assert!(0.0 <= p && p <= 1.0, "Bernoulli::new() is called with p outside of [0, 1]");
+        Bernoulli { p: p }
+    }
+}
+
+impl Distribution for Bernoulli {
+    type Value = u8;
+
+    #[inline]
+    fn mean(&self) -> f64 { self.p }
+
+    #[inline]
+    fn var(&self) -> f64 { self.p * (1.0 - self.p) }
+
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let q = 1.0 - self.p;
+        (q - self.p) / (self.p * q).sqrt()
+    }
+
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let q = 1.0 - self.p;
+        (1.0 - 6.0 * self.p * q) / (self.p * q)
+    }
+
+    #[inline]
+    fn median(&self) -> f64 {
+        if self.p < 0.5 { 0.0 } else if self.p > 0.5 { 1.0 } else { 0.5 }
+    }
+
+    #[inline]
+    fn modes(&self) -> Vec<u8> {
+        if self.p < 0.5 { vec![0] }
+        else if self.p > 0.5 { vec![1] }
+        else { vec![0, 1] }
+    }
+
+    #[inline]
+    fn entropy(&self) -> f64 {
+        if self.p == 0.0 || self.p == 1.0 { return 0.0; }
+        let q = 1.0 - self.p;
+        -self.p * self.p.ln() - q * q.ln()
+    }
+
+    #[inline]
+    fn cdf(&self, x: u8) -> f64 {
+        if x == 0 { 1.0 - self.p } else { 1.0 }
+    }
+
+    #[inline]
+    fn inv_cdf(&self, p: f64) -> u8 {
+        debug_assert!(0.0 <= p && p <= 1.0, "inv_cdf is called with p outside of [0, 1]");
+        if p <= 1.0 - self.p { 0 } else { 1 }
+    }
+
+    #[inline]
+    fn pdf(&self, x: u8) -> f64 {
+        match x {
+            0 => 1.0 - self.p,
+            1 => self.p,
+            _ => 0.0,
+        }
+    }
+
+    #[inline]
+    fn sample<S: Source>(&self, source: &mut S) -> u8 {
+        if source.read::<f64>() < self.p { 1 } else { 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Distribution;
+    use distributions::Bernoulli;
+
+    #[test]
+    #[should_panic]
+    fn invalid_p() {
+        Bernoulli::new(1.2);
+    }
+
+    #[test]
+    fn mean() {
+        let d = Bernoulli::new(0.25);
+        assert_eq!(d.mean(), 0.25);
+    }
+
+    #[test]
+    fn var() {
+        let d = Bernoulli::new(0.25);
+        assert_eq!(d.var(), 0.1875);
+    }
+
+    #[test]
+    fn pdf() {
+        let d = Bernoulli::new(0.25);
+        assert_eq!(d.pdf(0), 0.75);
+        assert_eq!(d.pdf(1), 0.25);
+    }
+
+    #[test]
+    fn cdf() {
+        let d = Bernoulli::new(0.25);
+        assert_eq!(d.cdf(0), 0.75);
+        assert_eq!(d.cdf(1), 1.0);
+    }
+}
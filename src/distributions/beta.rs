@@ -0,0 +1,259 @@
+extern crate sfunc;
+
+use random::Source;
+
+use {Distribution, Parameterized};
+
+/// A beta distribution.
+#[derive(Clone, Copy)]
+pub struct Beta {
+    /// The first shape parameter.
+    pub alpha: f64,
+    /// The second shape parameter.
+    pub beta: f64,
+    /// The left endpoint of the support.
+    pub a: f64,
+    /// The right endpoint of the support.
+    pub b: f64,
+}
+
+impl Beta {
+    /// Create a beta distribution with shape parameters `alpha` and `beta`
+    /// on the interval `[a, b]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha <= 0`, `beta <= 0`, or `a >= b`.
+    #[inline]
+    pub fn new(alpha: f64, beta: f64, a: f64, b: f64) -> Beta {
+        debug_assert!(alpha > 0.0, "Beta::new() is called with alpha <= 0");
+        debug_assert!(beta > 0.0, "Beta::new() is called with beta <= 0");
+        debug_assert!(a < b, "Beta::new() is called with a >= b");
+        Beta { alpha: alpha, beta: beta, a: a, b: b }
+    }
+}
+
+impl Distribution for Beta {
+    type Value = f64;
+
+    #[inline]
+    fn mean(&self) -> f64 {
+        self.a + (self.b - self.a) * self.alpha / (self.alpha + self.beta)
+    }
+
+    #[inline]
+    fn var(&self) -> f64 {
+        let sum = self.alpha + self.beta;
+        (self.b - self.a).powi(2) * self.alpha * self.beta / (sum.powi(2) * (sum + 1.0))
+    }
+
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let sum = self.alpha + self.beta;
+        2.0 * (self.beta - self.alpha) * (sum + 1.0).sqrt() /
+            ((sum + 2.0) * (self.alpha * self.beta).sqrt())
+    }
+
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let sum = self.alpha + self.beta;
+        let numerator = 6.0 * ((self.alpha - self.beta).powi(2) * (sum + 1.0) -
+            self.alpha * self.beta * (sum + 2.0));
+        numerator / (self.alpha * self.beta * (sum + 2.0) * (sum + 3.0))
+    }
+
+    #[inline]
+    fn median(&self) -> f64 {
+        self.inv_cdf(0.5)
+    }
+
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.alpha > 1.0 && self.beta > 1.0 {
+            vec![self.a + (self.b - self.a) * (self.alpha - 1.0) / (self.alpha + self.beta - 2.0)]
+        } else {
+            vec![]
+        }
+    }
+
+    fn entropy(&self) -> f64 {
+        use self::sfunc::{digamma, ln_beta};
+        let sum = self.alpha + self.beta;
+        ln_beta(self.alpha, self.beta) - (self.alpha - 1.0) * digamma(self.alpha) -
+            (self.beta - 1.0) * digamma(self.beta) + (sum - 2.0) * digamma(sum) +
+            (self.b - self.a).ln()
+    }
+
+    #[inline]
+    fn cdf(&self, x: f64) -> f64 {
+        use self::sfunc::{inc_beta, ln_beta};
+        inc_beta((x - self.a) / (self.b - self.a), self.alpha, self.beta,
+                 ln_beta(self.alpha, self.beta))
+    }
+
+    #[inline]
+    fn inv_cdf(&self, p: f64) -> f64 {
+        debug_assert!(0.0 <= p && p <= 1.0, "inv_cdf is called with p outside of [0, 1]");
+        use self::sfunc::{inv_inc_beta, ln_beta};
+        self.a + (self.b - self.a) * inv_inc_beta(p, self.alpha, self.beta,
+                                                   ln_beta(self.alpha, self.beta))
+    }
+
+    #[inline]
+    fn pdf(&self, x: f64) -> f64 {
+        use self::sfunc::beta;
+        if x < self.a || x > self.b { return 0.0; }
+        let z = (x - self.a) / (self.b - self.a);
+        z.powf(self.alpha - 1.0) * (1.0 - z).powf(self.beta - 1.0) /
+            (beta(self.alpha, self.beta) * (self.b - self.a))
+    }
+
+    #[inline]
+    fn ln_pdf(&self, x: f64) -> f64 {
+        use self::sfunc::ln_beta;
+        if x < self.a || x > self.b { return ::std::f64::NEG_INFINITY; }
+        let z = (x - self.a) / (self.b - self.a);
+        (self.alpha - 1.0) * z.ln() + (self.beta - 1.0) * (1.0 - z).ln() -
+            ln_beta(self.alpha, self.beta) - (self.b - self.a).ln()
+    }
+
+    #[inline]
+    fn sample<S: Source>(&self, source: &mut S) -> f64 {
+        use distributions::Gamma;
+        let x = Gamma::new(self.alpha, 1.0).sample(source);
+        let y = Gamma::new(self.beta, 1.0).sample(source);
+        self.a + (self.b - self.a) * x / (x + y)
+    }
+}
+
+impl Parameterized for Beta {
+    type Value = f64;
+
+    #[inline]
+    fn params(&self) -> Vec<f64> { vec![self.alpha, self.beta, self.a, self.b] }
+
+    #[inline]
+    fn from_params(params: &[f64]) -> Beta {
+        Beta::new(params[0], params[1], params[2], params[3])
+    }
+
+    /// Estimate `alpha` and `beta` by the method of moments, using the
+    /// observed range as the `[a, b]` support.
+    ///
+    /// This estimator is only well-posed for data whose rescaled variance
+    /// falls strictly below the Beta-feasible bound `z_mean * (1 -
+    /// z_mean)`; degenerate or bimodal data at or beyond that bound (e.g.
+    /// a point mass at each end of the range) would otherwise drive
+    /// `alpha`/`beta` to zero or below, which is clamped to a small
+    /// positive floor instead of panicking in `Beta::new`. Constant data
+    /// (including a single-element sample) has zero range, which would
+    /// divide by zero before that clamp even applies and still leaves
+    /// `a == b`; that case falls back to a uniform prior on an arbitrary
+    /// interval centered on the constant value instead.
+    fn fit(data: &[f64]) -> Beta {
+        let n = data.len() as f64;
+        let a = data.iter().cloned().fold(::std::f64::INFINITY, f64::min);
+        let b = data.iter().cloned().fold(::std::f64::NEG_INFINITY, f64::max);
+        if b <= a {
+            return Beta::new(1.0, 1.0, a - 0.5, b + 0.5);
+        }
+
+        let mean = data.iter().sum::<f64>() / n;
+        let var = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        let z_mean = (mean - a) / (b - a);
+        let z_var = var / (b - a).powi(2);
+        let common = (z_mean * (1.0 - z_mean) / z_var - 1.0).max(::std::f64::EPSILON);
+        Beta::new((z_mean * common).max(::std::f64::EPSILON),
+                  ((1.0 - z_mean) * common).max(::std::f64::EPSILON), a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Distribution;
+    use Parameterized;
+    use distributions::Beta;
+
+    #[test]
+    fn fit_gives_positive_shapes() {
+        let source = Beta::new(2.0, 5.0, 0.0, 1.0);
+        let mean = source.mean();
+        let var = source.var();
+        // Construct synthetic data centered on the source distribution's
+        // mean, one standard deviation to either side of it.
+        let data = vec![mean - var.sqrt(), mean, mean, mean + var.sqrt()];
+        let fitted = Beta::fit(&data);
+        assert!(fitted.alpha > 0.0 && fitted.beta > 0.0);
+    }
+
+    #[test]
+    fn fit_on_degenerate_bimodal_data_does_not_panic() {
+        // Bimodal data at the extremes of its own range drives the raw
+        // method-of-moments estimate to alpha == beta == 0 exactly; fit
+        // must clamp rather than hand that to Beta::new.
+        let data = vec![0.0, 0.0, 10.0, 10.0];
+        let fitted = Beta::fit(&data);
+        assert!(fitted.alpha > 0.0 && fitted.beta > 0.0);
+    }
+
+    #[test]
+    fn fit_on_constant_data_does_not_panic() {
+        // Zero range (`a == b`) would divide by zero before the alpha/beta
+        // clamp even applies, and Beta::new itself panics on a >= b.
+        let fitted = Beta::fit(&[5.0, 5.0, 5.0, 5.0]);
+        assert!(fitted.a < fitted.b);
+        assert!(fitted.alpha > 0.0 && fitted.beta > 0.0);
+    }
+
+    #[test]
+    fn fit_on_single_point_does_not_panic() {
+        let fitted = Beta::fit(&[3.0]);
+        assert!(fitted.a < fitted.b);
+        assert!(fitted.alpha > 0.0 && fitted.beta > 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_alpha() {
+        Beta::new(-1.0, 2.0, 0.0, 1.0);
+    }
+
+    #[test]
+    fn mean() {
+        let d = Beta::new(2.0, 3.0, 0.0, 1.0);
+        assert_eq!(d.mean(), 0.4);
+    }
+
+    #[test]
+    fn ln_pdf() {
+        let d = Beta::new(2.0, 3.0, -1.0, 2.0);
+        for &x in &[-0.5, 0.0, 0.5, 1.0, 1.5] {
+            assert!((d.ln_pdf(x) - d.pdf(x).ln()).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn cdf() {
+        let d = Beta::new(2.0, 3.0, -1.0, 2.0);
+
+        let x = vec![-1.00, -0.85, -0.70, -0.55, -0.40, -0.25, -0.10, 0.05,
+                     0.20, 0.35, 0.50, 0.65, 0.80, 0.95, 1.10, 1.25, 1.40,
+                     1.55, 1.70, 1.85, 2.00];
+        let p = vec![0.000000000000000e+00, 1.401875000000000e-02,
+                     5.230000000000002e-02, 1.095187500000000e-01,
+                     1.807999999999999e-01, 2.617187500000001e-01,
+                     3.483000000000000e-01, 4.370187500000001e-01,
+                     5.248000000000003e-01, 6.090187500000001e-01,
+                     6.875000000000000e-01, 7.585187500000001e-01,
+                     8.208000000000000e-01, 8.735187499999999e-01,
+                     9.163000000000000e-01, 9.492187500000000e-01,
+                     9.728000000000000e-01, 9.880187500000001e-01,
+                     9.963000000000000e-01, 9.995187500000000e-01,
+                     1.000000000000000e+00];
+
+        for (&x, &p) in x.iter().zip(p.iter()) {
+            assert!((d.cdf(x) - p).abs() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+extern crate sfunc;
+
+use random::Source;
+
+use Distribution;
+
+/// A binomial distribution.
+#[derive(Clone, Copy)]
+pub struct Binomial {
+    /// The number of trials.
+    pub n: u32,
+    /// The probability of success.
+    pub p: f64,
+}
+
+impl Binomial {
+    /// Create a binomial distribution with `n` trials and success
+    /// probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in `[0, 1]`.
+    #[inline]
+    pub fn new(n: u32, p: f64) -> Binomial {
+        debug_assert!(0.0 <= p && p <= 1.0, "Binomial::new() is called with p outside of [0, 1]");
+        Binomial { n: n, p: p }
+    }
+}
+
+impl Distribution for Binomial {
+    type Value = u32;
+
+    #[inline]
+    fn mean(&self) -> f64 { self.n as f64 * self.p }
+
+    #[inline]
+    fn var(&self) -> f64 { self.n as f64 * self.p * (1.0 - self.p) }
+
+    #[inline]
+    fn skewness(&self) -> f64 {
+        let q = 1.0 - self.p;
+        (q - self.p) / (self.n as f64 * self.p * q).sqrt()
+    }
+
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        let q = 1.0 - self.p;
+        (1.0 - 6.0 * self.p * q) / (self.n as f64 * self.p * q)
+    }
+
+    #[inline]
+    fn median(&self) -> f64 { (self.n as f64 * self.p).round() }
+
+    #[inline]
+    fn modes(&self) -> Vec<u32> {
+        let m = (self.n as f64 + 1.0) * self.p;
+        let k = m.floor() as u32;
+        if k > self.n { vec![self.n] }
+        else if k > 0 && m == k as f64 { vec![k - 1, k] }
+        else { vec![k] }
+    }
+
+    fn entropy(&self) -> f64 {
+        (0..(self.n + 1)).map(|k| {
+            let p = self.pdf(k);
+            if p == 0.0 { 0.0 } else { -p * p.ln() }
+        }).sum()
+    }
+
+    fn cdf(&self, x: u32) -> f64 {
+        use self::sfunc::inc_beta;
+        if x >= self.n { return 1.0; }
+        use self::sfunc::ln_beta;
+        let q = 1.0 - self.p;
+        let k = x as f64 + 1.0;
+        inc_beta(q, self.n as f64 - x as f64, k, ln_beta(self.n as f64 - x as f64, k))
+    }
+
+    fn inv_cdf(&self, p: f64) -> u32 {
+        debug_assert!(0.0 <= p && p <= 1.0, "inv_cdf is called with p outside of [0, 1]");
+        let mut cumulative = 0.0;
+        for k in 0..(self.n + 1) {
+            cumulative += self.pdf(k);
+            if cumulative >= p { return k; }
+        }
+        self.n
+    }
+
+    fn pdf(&self, x: u32) -> f64 {
+        if x > self.n { return 0.0; }
+        // `0 * ln(0)` is `NaN` in IEEE754, so the all-or-nothing ends of
+        // the support need their own branches rather than falling through
+        // to the log-space expression below.
+        if self.p == 0.0 { return if x == 0 { 1.0 } else { 0.0 }; }
+        if self.p == 1.0 { return if x == self.n { 1.0 } else { 0.0 }; }
+        use self::sfunc::ln_gamma;
+        let n = self.n as f64;
+        let k = x as f64;
+        let ln_coeff = ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0);
+        (ln_coeff + k * self.p.ln() + (n - k) * (1.0 - self.p).ln()).exp()
+    }
+
+    #[inline]
+    fn sample<S: Source>(&self, source: &mut S) -> u32 {
+        (0..self.n).filter(|_| source.read::<f64>() < self.p).count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Distribution;
+    use distributions::Binomial;
+
+    #[test]
+    #[should_panic]
+    fn invalid_p() {
+        Binomial::new(10, 1.2);
+    }
+
+    #[test]
+    fn mean() {
+        let d = Binomial::new(10, 0.25);
+        assert_eq!(d.mean(), 2.5);
+    }
+
+    #[test]
+    fn var() {
+        let d = Binomial::new(10, 0.25);
+        assert_eq!(d.var(), 1.875);
+    }
+
+    #[test]
+    fn pdf_sums_to_one() {
+        let d = Binomial::new(10, 0.3);
+        let sum: f64 = (0..11).map(|k| d.pdf(k)).sum();
+        assert!((sum - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn pdf_at_p_zero() {
+        let d = Binomial::new(5, 0.0);
+        assert_eq!(d.pdf(0), 1.0);
+        assert_eq!(d.pdf(1), 0.0);
+    }
+
+    #[test]
+    fn pdf_at_p_one() {
+        let d = Binomial::new(5, 1.0);
+        assert_eq!(d.pdf(5), 1.0);
+        assert_eq!(d.pdf(4), 0.0);
+    }
+
+    #[test]
+    fn entropy_of_degenerate_is_zero() {
+        assert_eq!(Binomial::new(5, 0.0).entropy(), 0.0);
+        assert_eq!(Binomial::new(5, 1.0).entropy(), 0.0);
+    }
+
+    #[test]
+    fn entropy_of_fair_coin() {
+        let d = Binomial::new(1, 0.5);
+        assert!((d.entropy() - 2.0f64.ln()).abs() < 1e-12);
+    }
+}
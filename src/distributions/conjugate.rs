@@ -0,0 +1,197 @@
+extern crate sfunc;
+
+use distributions::{Bernoulli, Beta, Binomial, Exponential, Gamma, Gaussian};
+
+/// A prior distribution with a closed-form posterior for a given
+/// likelihood.
+///
+/// `X` is the type of a single observation and `L` identifies the
+/// likelihood distribution this prior is conjugate to, which lets the
+/// same prior type (e.g. `Gamma`) be conjugate to more than one
+/// likelihood without a conflicting-impl error.
+pub trait ConjugatePrior<X, L> {
+    /// The type of the posterior distribution.
+    type Posterior;
+
+    /// Compute the posterior distribution after observing `data`.
+    fn posterior(&self, data: &[X]) -> Self::Posterior;
+
+    /// Compute the log marginal likelihood of `data` under this prior.
+    fn ln_m(&self, data: &[X]) -> f64;
+
+    /// Compute the posterior-predictive density of `x` given `data`.
+    fn pp(&self, x: X, data: &[X]) -> f64;
+}
+
+impl ConjugatePrior<f64, Exponential> for Gamma {
+    type Posterior = Gamma;
+
+    #[inline]
+    fn posterior(&self, data: &[f64]) -> Gamma {
+        let n = data.len() as f64;
+        let s: f64 = data.iter().sum();
+        Gamma::new(self.shape + n, self.rate + s)
+    }
+
+    fn ln_m(&self, data: &[f64]) -> f64 {
+        use self::sfunc::ln_gamma;
+        let post = self.posterior(data);
+        ln_gamma(post.shape) - ln_gamma(self.shape) +
+            self.shape * self.rate.ln() - post.shape * post.rate.ln()
+    }
+
+    fn pp(&self, x: f64, data: &[f64]) -> f64 {
+        if x < 0.0 { return 0.0; }
+        let mut extended = data.to_vec();
+        extended.push(x);
+        (self.ln_m(&extended) - self.ln_m(data)).exp()
+    }
+}
+
+impl ConjugatePrior<bool, Bernoulli> for Beta {
+    type Posterior = Beta;
+
+    #[inline]
+    fn posterior(&self, data: &[bool]) -> Beta {
+        let successes = data.iter().filter(|&&x| x).count() as f64;
+        let failures = data.len() as f64 - successes;
+        Beta::new(self.alpha + successes, self.beta + failures, 0.0, 1.0)
+    }
+
+    fn ln_m(&self, data: &[bool]) -> f64 {
+        use self::sfunc::ln_beta;
+        let post = self.posterior(data);
+        ln_beta(post.alpha, post.beta) - ln_beta(self.alpha, self.beta)
+    }
+
+    fn pp(&self, x: bool, data: &[bool]) -> f64 {
+        let post = self.posterior(data);
+        let p = post.alpha / (post.alpha + post.beta);
+        if x { p } else { 1.0 - p }
+    }
+}
+
+/// Compute `ln(C(n, k))`, the log of the binomial coefficient.
+fn ln_choose(n: f64, k: f64) -> f64 {
+    use self::sfunc::ln_gamma;
+    ln_gamma(n + 1.0) - ln_gamma(k + 1.0) - ln_gamma(n - k + 1.0)
+}
+
+/// A single Binomial observation: `(successes, trials)`.
+impl ConjugatePrior<(u32, u32), Binomial> for Beta {
+    type Posterior = Beta;
+
+    fn posterior(&self, data: &[(u32, u32)]) -> Beta {
+        let successes: f64 = data.iter().map(|&(k, _)| k as f64).sum();
+        let trials: f64 = data.iter().map(|&(_, n)| n as f64).sum();
+        Beta::new(self.alpha + successes, self.beta + (trials - successes), 0.0, 1.0)
+    }
+
+    fn ln_m(&self, data: &[(u32, u32)]) -> f64 {
+        use self::sfunc::ln_beta;
+        let post = self.posterior(data);
+        let ln_coeffs: f64 = data.iter().map(|&(k, n)| ln_choose(n as f64, k as f64)).sum();
+        ln_coeffs + ln_beta(post.alpha, post.beta) - ln_beta(self.alpha, self.beta)
+    }
+
+    /// Compute the exact Beta-Binomial compound pmf of `x = (k, n)` under
+    /// the posterior given `data`, i.e. `C(n, k) * B(alpha' + k, beta' +
+    /// n - k) / B(alpha', beta')` for posterior shapes `alpha', beta'`.
+    /// A point estimate such as `Binomial::new(n, post.mean()).pdf(k)` is
+    /// only exact when the Binomial pmf is linear in `p` (`n == 1`); for
+    /// `n > 1` it is a biased approximation to this compound density.
+    fn pp(&self, x: (u32, u32), data: &[(u32, u32)]) -> f64 {
+        use self::sfunc::ln_beta;
+        let (k, n) = x;
+        let post = self.posterior(data);
+        (ln_choose(n as f64, k as f64) + ln_beta(post.alpha + k as f64, post.beta + (n - k) as f64) -
+            ln_beta(post.alpha, post.beta)).exp()
+    }
+}
+
+impl ConjugatePrior<f64, Gaussian> for Gamma {
+    type Posterior = Gamma;
+
+    /// Update a `Gamma` prior over the precision of a zero-mean Gaussian.
+    ///
+    /// The mean is assumed known and equal to `0`; center observations
+    /// before calling this (i.e. pass `x - mu`) when the mean is
+    /// estimated separately.
+    #[inline]
+    fn posterior(&self, data: &[f64]) -> Gamma {
+        let n = data.len() as f64;
+        let sum_sq: f64 = data.iter().map(|x| x * x).sum();
+        Gamma::new(self.shape + 0.5 * n, self.rate + 0.5 * sum_sq)
+    }
+
+    fn ln_m(&self, data: &[f64]) -> f64 {
+        use self::sfunc::ln_gamma;
+        let n = data.len() as f64;
+        let post = self.posterior(data);
+        ln_gamma(post.shape) - ln_gamma(self.shape) +
+            self.shape * self.rate.ln() - post.shape * post.rate.ln() -
+            0.5 * n * (2.0 * ::std::f64::consts::PI).ln()
+    }
+
+    fn pp(&self, x: f64, data: &[f64]) -> f64 {
+        let mut extended = data.to_vec();
+        extended.push(x);
+        (self.ln_m(&extended) - self.ln_m(data)).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use distributions::{Beta, ConjugatePrior, Gamma};
+
+    #[test]
+    fn gamma_exponential_ln_m() {
+        let prior = Gamma::new(2.0, 1.0);
+        let data = vec![1.0];
+        assert!((prior.ln_m(&data) - -1.3862943611198908).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gamma_exponential_pp() {
+        let prior = Gamma::new(2.0, 1.0);
+        let data = vec![1.0];
+        assert!((prior.pp(2.0, &data) - 0.09375).abs() < 1e-9);
+    }
+
+    #[test]
+    fn beta_bernoulli_ln_m_and_pp() {
+        let prior = Beta::new(1.0, 1.0, 0.0, 1.0);
+        let data = vec![true, false, true];
+        assert!((prior.ln_m(&data) - -2.484906649788).abs() < 1e-9);
+        assert!((prior.pp(true, &data) - 0.6).abs() < 1e-12);
+        assert!((prior.pp(false, &data) - 0.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn beta_binomial_ln_m_includes_log_choose() {
+        // Regression test: the log-binomial-coefficient term is easy to
+        // drop since it vanishes for the n == 1 Bernoulli case but not
+        // here.
+        let prior = Beta::new(1.0, 1.0, 0.0, 1.0);
+        let data = vec![(2u32, 5u32)];
+        assert!((prior.ln_m(&data) - -1.7917594692280554).abs() < 1e-9);
+    }
+
+    #[test]
+    fn beta_binomial_pp_is_exact_compound_pmf() {
+        // Regression test: a plug-in point estimate at the posterior mean
+        // is only exact for n == 1; this checks the true Beta-Binomial
+        // compound density for n > 1.
+        let prior = Beta::new(1.0, 1.0, 0.0, 1.0);
+        let data = vec![(2u32, 5u32)];
+        assert!((prior.pp((1u32, 3u32), &data) - 5.0 / 14.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn gamma_gaussian_ln_m_and_pp() {
+        let prior = Gamma::new(2.0, 1.0);
+        let data = vec![1.0, -1.0];
+        assert!((prior.ln_m(&data) - -3.224171427529236).abs() < 1e-9);
+        assert!((prior.pp(1.0, &data) - 0.21466252583997975).abs() < 1e-9);
+    }
+}
@@ -1,14 +1,12 @@
-use rand::distributions::{Exp, IndependentSample};
+use random::Source;
 
-use {Distribution, Generator};
+use {Distribution, Parameterized};
 
 /// An exponential distribution.
 #[derive(Clone, Copy)]
 pub struct Exponential {
     /// The rate parameter.
     pub lambda: f64,
-
-    sampler: Exp,
 }
 
 impl Exponential {
@@ -20,7 +18,7 @@ impl Exponential {
     #[inline]
     pub fn new(lambda: f64) -> Exponential {
         debug_assert!(lambda > 0.0, "Exponental::new() is called with lambda <= 0");
-        Exponential { lambda: lambda, sampler: Exp::new(lambda) }
+        Exponential { lambda: lambda }
     }
 }
 
@@ -60,6 +58,12 @@ impl Distribution for Exponential {
         else { self.lambda * (-self.lambda * x).exp() }
     }
 
+    #[inline]
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 { ::std::f64::NEG_INFINITY }
+        else { self.lambda.ln() - self.lambda * x }
+    }
+
     #[inline]
     fn cdf(&self, x: f64) -> f64 {
         if x <= 0.0 { 0.0 }
@@ -73,8 +77,23 @@ impl Distribution for Exponential {
     }
 
     #[inline]
-    fn sample<G: Generator>(&self, generator: &mut G) -> f64 {
-        self.sampler.ind_sample(generator)
+    fn sample<S: Source>(&self, source: &mut S) -> f64 {
+        self.inv_cdf(source.read::<f64>())
+    }
+}
+
+impl Parameterized for Exponential {
+    type Value = f64;
+
+    #[inline]
+    fn params(&self) -> Vec<f64> { vec![self.lambda] }
+
+    #[inline]
+    fn from_params(params: &[f64]) -> Exponential { Exponential::new(params[0]) }
+
+    fn fit(data: &[f64]) -> Exponential {
+        let mean = data.iter().sum::<f64>() / data.len() as f64;
+        Exponential::new(mean.recip())
     }
 }
 
@@ -84,6 +103,7 @@ mod tests {
     use assert;
 
     use Distribution;
+    use Parameterized;
     use distributions::Exponential;
 
     #[test]
@@ -92,6 +112,20 @@ mod tests {
         Exponential::new(-1.0);
     }
 
+    #[test]
+    fn fit() {
+        let data = vec![0.5, 1.0, 1.5, 2.0];
+        let d = Exponential::fit(&data);
+        assert_eq!(d.lambda, 4.0 / 5.0);
+    }
+
+    #[test]
+    fn params_round_trip() {
+        let d = Exponential::new(2.0);
+        let d = Exponential::from_params(&d.params());
+        assert_eq!(d.lambda, 2.0);
+    }
+
     #[test]
     fn mean() {
         let d = Exponential::new(2.0);
@@ -157,6 +191,22 @@ mod tests {
         assert::within(&x.iter().map(|&x| exponential.pdf(x)).collect::<Vec<_>>(), &p, 1e-15);
     }
 
+    #[test]
+    fn ln_pdf() {
+        let exponential = Exponential::new(2.0);
+        let x = vec![-1.0, 0.0, 0.5, 1.0, 12.0];
+        for &x in &x {
+            assert::close(exponential.ln_pdf(x), exponential.pdf(x).ln(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn ln_pdf_far_tail_does_not_underflow() {
+        let exponential = Exponential::new(2.0);
+        assert!(exponential.ln_pdf(1000.0).is_finite());
+        assert_eq!(exponential.pdf(1000.0), 0.0);
+    }
+
     #[test]
     fn cdf() {
         let exponential = Exponential::new(2.0);
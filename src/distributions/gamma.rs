@@ -0,0 +1,128 @@
+extern crate sfunc;
+
+use random::Source;
+
+use Distribution;
+
+/// A gamma distribution.
+#[derive(Clone, Copy)]
+pub struct Gamma {
+    /// The shape parameter.
+    pub shape: f64,
+    /// The rate parameter.
+    pub rate: f64,
+}
+
+impl Gamma {
+    /// Create a gamma distribution with shape `shape` and rate `rate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape <= 0` or `rate <= 0`.
+    #[inline]
+    pub fn new(shape: f64, rate: f64) -> Gamma {
+        debug_assert!(shape > 0.0, "Gamma::new() is called with shape <= 0");
+        debug_assert!(rate > 0.0, "Gamma::new() is called with rate <= 0");
+        Gamma { shape: shape, rate: rate }
+    }
+}
+
+impl Distribution for Gamma {
+    type Value = f64;
+
+    #[inline]
+    fn mean(&self) -> f64 { self.shape / self.rate }
+
+    #[inline]
+    fn var(&self) -> f64 { self.shape / self.rate.powi(2) }
+
+    #[inline]
+    fn skewness(&self) -> f64 { 2.0 / self.shape.sqrt() }
+
+    #[inline]
+    fn kurtosis(&self) -> f64 { 6.0 / self.shape }
+
+    #[inline]
+    fn median(&self) -> f64 { self.inv_cdf(0.5) }
+
+    #[inline]
+    fn modes(&self) -> Vec<f64> {
+        if self.shape >= 1.0 { vec![(self.shape - 1.0) / self.rate] } else { vec![0.0] }
+    }
+
+    fn entropy(&self) -> f64 {
+        use self::sfunc::{digamma, ln_gamma};
+        self.shape - self.rate.ln() + ln_gamma(self.shape) + (1.0 - self.shape) * digamma(self.shape)
+    }
+
+    #[inline]
+    fn cdf(&self, x: f64) -> f64 {
+        use self::sfunc::inc_gamma;
+        if x <= 0.0 { 0.0 } else { inc_gamma(self.rate * x, self.shape) }
+    }
+
+    #[inline]
+    fn inv_cdf(&self, p: f64) -> f64 {
+        debug_assert!(0.0 <= p && p <= 1.0, "inv_cdf is called with p outside of [0, 1]");
+        use self::sfunc::inv_inc_gamma;
+        inv_inc_gamma(p, self.shape) / self.rate
+    }
+
+    #[inline]
+    fn pdf(&self, x: f64) -> f64 {
+        use self::sfunc::ln_gamma;
+        if x < 0.0 { return 0.0; }
+        (self.shape * self.rate.ln() - ln_gamma(self.shape) +
+            (self.shape - 1.0) * x.ln() - self.rate * x).exp()
+    }
+
+    fn sample<S: Source>(&self, source: &mut S) -> f64 {
+        // Marsaglia-Tsang method.
+        use distributions::Gaussian;
+        let standard = Gaussian::new(0.0, 1.0);
+        if self.shape < 1.0 {
+            let u = source.read::<f64>();
+            return Gamma::new(self.shape + 1.0, self.rate).sample(source) * u.powf(1.0 / self.shape);
+        }
+        let d = self.shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let mut x;
+            let mut v;
+            loop {
+                x = standard.sample(source);
+                v = 1.0 + c * x;
+                if v > 0.0 { break; }
+            }
+            v = v * v * v;
+            let u = source.read::<f64>();
+            if u < 1.0 - 0.0331 * x.powi(4) || u.ln() < 0.5 * x * x + d * (1.0 - v + v.ln()) {
+                return d * v / self.rate;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Distribution;
+    use distributions::Gamma;
+
+    #[test]
+    #[should_panic]
+    fn invalid_shape() {
+        Gamma::new(-1.0, 1.0);
+    }
+
+    #[test]
+    fn mean() {
+        let d = Gamma::new(2.0, 4.0);
+        assert_eq!(d.mean(), 0.5);
+    }
+
+    #[test]
+    fn var() {
+        let d = Gamma::new(2.0, 4.0);
+        assert_eq!(d.var(), 0.125);
+    }
+}
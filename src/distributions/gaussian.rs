@@ -0,0 +1,138 @@
+extern crate sfunc;
+
+use random::Source;
+
+use {Distribution, Parameterized};
+
+/// A Gaussian distribution.
+#[derive(Clone, Copy)]
+pub struct Gaussian {
+    /// The mean.
+    pub mu: f64,
+    /// The standard deviation.
+    pub sigma: f64,
+}
+
+impl Gaussian {
+    /// Create a Gaussian distribution with mean `mu` and standard deviation
+    /// `sigma`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sigma <= 0`.
+    #[inline]
+    pub fn new(mu: f64, sigma: f64) -> Gaussian {
+        debug_assert!(sigma > 0.0, "Gaussian::new() is called with sigma <= 0");
+        Gaussian { mu: mu, sigma: sigma }
+    }
+}
+
+impl Distribution for Gaussian {
+    type Value = f64;
+
+    #[inline]
+    fn mean(&self) -> f64 { self.mu }
+
+    #[inline]
+    fn var(&self) -> f64 { self.sigma * self.sigma }
+
+    #[inline]
+    fn skewness(&self) -> f64 { 0.0 }
+
+    #[inline]
+    fn kurtosis(&self) -> f64 { 0.0 }
+
+    #[inline]
+    fn median(&self) -> f64 { self.mu }
+
+    #[inline]
+    fn modes(&self) -> Vec<f64> { vec![self.mu] }
+
+    #[inline]
+    fn entropy(&self) -> f64 {
+        0.5 * (2.0 * ::std::f64::consts::PI * ::std::f64::consts::E * self.var()).ln()
+    }
+
+    #[inline]
+    fn cdf(&self, x: f64) -> f64 {
+        use self::sfunc::erf;
+        0.5 * (1.0 + erf((x - self.mu) / (self.sigma * ::std::f64::consts::SQRT_2)))
+    }
+
+    #[inline]
+    fn inv_cdf(&self, p: f64) -> f64 {
+        debug_assert!(0.0 <= p && p <= 1.0, "inv_cdf is called with p outside of [0, 1]");
+        use self::sfunc::erf_inv;
+        self.mu + self.sigma * ::std::f64::consts::SQRT_2 * erf_inv(2.0 * p - 1.0)
+    }
+
+    #[inline]
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mu) / self.sigma;
+        (-0.5 * z * z).exp() / (self.sigma * (2.0 * ::std::f64::consts::PI).sqrt())
+    }
+
+    fn sample<S: Source>(&self, source: &mut S) -> f64 {
+        // Box-Muller transform.
+        let u1: f64 = source.read::<f64>();
+        let u2: f64 = source.read::<f64>();
+        let r = (-2.0 * u1.max(::std::f64::MIN_POSITIVE).ln()).sqrt();
+        self.mu + self.sigma * r * (2.0 * ::std::f64::consts::PI * u2).cos()
+    }
+}
+
+impl Parameterized for Gaussian {
+    type Value = f64;
+
+    #[inline]
+    fn params(&self) -> Vec<f64> { vec![self.mu, self.sigma] }
+
+    #[inline]
+    fn from_params(params: &[f64]) -> Gaussian { Gaussian::new(params[0], params[1]) }
+
+    fn fit(data: &[f64]) -> Gaussian {
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let var = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        Gaussian::new(mean, var.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Distribution;
+    use Parameterized;
+    use distributions::Gaussian;
+
+    #[test]
+    #[should_panic]
+    fn invalid_sigma() {
+        Gaussian::new(0.0, -1.0);
+    }
+
+    #[test]
+    fn fit() {
+        let data = vec![1.0, 2.0, 3.0, 4.0];
+        let d = Gaussian::fit(&data);
+        assert_eq!(d.mu, 2.5);
+        assert_eq!(d.sigma, 1.25f64.sqrt());
+    }
+
+    #[test]
+    fn mean() {
+        let d = Gaussian::new(1.0, 2.0);
+        assert_eq!(d.mean(), 1.0);
+    }
+
+    #[test]
+    fn var() {
+        let d = Gaussian::new(1.0, 2.0);
+        assert_eq!(d.var(), 4.0);
+    }
+
+    #[test]
+    fn pdf_at_mean() {
+        let d = Gaussian::new(0.0, 1.0);
+        assert!((d.pdf(0.0) - 0.3989422804014327).abs() < 1e-12);
+    }
+}
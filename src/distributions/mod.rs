@@ -0,0 +1,205 @@
+//! Probability distributions.
+
+use random::Source;
+
+/// A distribution that has a probability density (or mass) function.
+pub trait HasDensity {
+    type Value;
+
+    /// Compute the probability density function.
+    fn pdf(&self, Self::Value) -> f64;
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// The default implementation takes the logarithm of `pdf`, which can
+    /// underflow to zero (and hence this to negative infinity) sooner than
+    /// an analytic expression would; override it where that precision
+    /// matters, e.g. log-likelihood accumulation across many samples.
+    #[inline]
+    fn ln_pdf(&self, x: Self::Value) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// A distribution that can be sampled from.
+pub trait Sampleable {
+    type Value;
+
+    /// Draw a sample.
+    fn sample<S>(&self, &mut S) -> Self::Value where S: Source;
+}
+
+/// A distribution with a cumulative distribution function.
+pub trait Cdf {
+    type Value;
+
+    /// Compute the cumulative distribution function.
+    fn cdf(&self, Self::Value) -> f64;
+
+    /// Compute the inverse of the cumulative distribution function.
+    fn inv_cdf(&self, f64) -> Self::Value;
+}
+
+/// A distribution with closed-form moments.
+pub trait Moments {
+    /// Compute the expected value.
+    fn mean(&self) -> f64;
+
+    /// Compute the variance.
+    fn var(&self) -> f64;
+
+    /// Compute the standard deviation.
+    #[inline]
+    fn sd(&self) -> f64 {
+        self.var().sqrt()
+    }
+
+    /// Compute the skewness.
+    fn skewness(&self) -> f64;
+
+    /// Compute the excess kurtosis.
+    fn kurtosis(&self) -> f64;
+}
+
+/// A probability distribution.
+///
+/// This is a convenience trait bundling the methods of `HasDensity`,
+/// `Sampleable`, `Cdf`, and `Moments` together with `median`, `modes`, and
+/// `entropy`,
+/// which do not belong to any one of them. Implementing `Distribution`
+/// alone is enough to satisfy all four (see the blanket impls below); new
+/// distributions that cannot support the full set, such as one that can
+/// only be sampled from, should implement the narrower traits directly
+/// instead.
+pub trait Distribution {
+    type Value;
+
+    /// Compute the expected value.
+    fn mean(&self) -> f64;
+
+    /// Compute the variance.
+    fn var(&self) -> f64;
+
+    /// Compute the standard deviation.
+    #[inline]
+    fn sd(&self) -> f64 {
+        self.var().sqrt()
+    }
+
+    /// Compute the skewness.
+    fn skewness(&self) -> f64;
+
+    /// Compute the excess kurtosis.
+    fn kurtosis(&self) -> f64;
+
+    /// Compute the median.
+    fn median(&self) -> f64;
+
+    /// Compute the modes.
+    fn modes(&self) -> Vec<Self::Value>;
+
+    /// Compute the differential entropy in nats.
+    fn entropy(&self) -> f64;
+
+    /// Compute the cumulative distribution function.
+    fn cdf(&self, Self::Value) -> f64;
+
+    /// Compute the inverse of the cumulative distribution function.
+    fn inv_cdf(&self, f64) -> Self::Value;
+
+    /// Compute the probability density function.
+    fn pdf(&self, Self::Value) -> f64;
+
+    /// Compute the natural logarithm of the probability density function.
+    ///
+    /// See `HasDensity::ln_pdf` for why a distribution may want to
+    /// override the default.
+    #[inline]
+    fn ln_pdf(&self, x: Self::Value) -> f64 {
+        self.pdf(x).ln()
+    }
+
+    /// Draw a sample.
+    fn sample<S>(&self, &mut S) -> Self::Value where S: Source;
+}
+
+impl<T: Distribution> HasDensity for T {
+    type Value = T::Value;
+
+    #[inline]
+    fn pdf(&self, x: Self::Value) -> f64 {
+        Distribution::pdf(self, x)
+    }
+
+    #[inline]
+    fn ln_pdf(&self, x: Self::Value) -> f64 {
+        Distribution::ln_pdf(self, x)
+    }
+}
+
+impl<T: Distribution> Sampleable for T {
+    type Value = T::Value;
+
+    #[inline]
+    fn sample<S>(&self, source: &mut S) -> Self::Value where S: Source {
+        Distribution::sample(self, source)
+    }
+}
+
+impl<T: Distribution> Cdf for T {
+    type Value = T::Value;
+
+    #[inline]
+    fn cdf(&self, x: Self::Value) -> f64 {
+        Distribution::cdf(self, x)
+    }
+
+    #[inline]
+    fn inv_cdf(&self, p: f64) -> Self::Value {
+        Distribution::inv_cdf(self, p)
+    }
+}
+
+impl<T: Distribution> Moments for T {
+    #[inline]
+    fn mean(&self) -> f64 {
+        Distribution::mean(self)
+    }
+
+    #[inline]
+    fn var(&self) -> f64 {
+        Distribution::var(self)
+    }
+
+    #[inline]
+    fn skewness(&self) -> f64 {
+        Distribution::skewness(self)
+    }
+
+    #[inline]
+    fn kurtosis(&self) -> f64 {
+        Distribution::kurtosis(self)
+    }
+}
+
+pub use self::bernoulli::Bernoulli;
+pub use self::beta::Beta;
+pub use self::binomial::Binomial;
+pub use self::conjugate::ConjugatePrior;
+pub use self::exponential::Exponential;
+pub use self::gamma::Gamma;
+pub use self::gaussian::Gaussian;
+pub use self::order::{sorted_uniforms, OrderStatistic};
+pub use self::parameterized::Parameterized;
+pub use self::series::accelerate;
+
+mod bernoulli;
+mod beta;
+mod binomial;
+mod conjugate;
+mod exponential;
+mod gamma;
+mod gaussian;
+mod order;
+mod parameterized;
+mod series;
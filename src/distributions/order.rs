@@ -0,0 +1,96 @@
+use random::Source;
+
+use distributions::{Cdf, Exponential, Sampleable};
+
+/// Draw `n` i.i.d. Uniform(0, 1) variates already in ascending order, in
+/// `O(n)` time.
+///
+/// If `e_0, ..., e_n` are i.i.d. Exponential(1) and `T = e_0 + ... +
+/// e_n`, then `(e_0 + ... + e_k) / T` for `k = 0..n-1` are distributed as
+/// the `n` order statistics of Uniform(0, 1), already sorted. This is
+/// much faster than drawing `n` uniforms and sorting them for large `n`.
+pub fn sorted_uniforms<S: Source>(n: usize, source: &mut S) -> Vec<f64> {
+    let exponential = Exponential::new(1.0);
+    let mut cumulative = 0.0;
+    let mut draws = Vec::with_capacity(n);
+    for _ in 0..n {
+        cumulative += exponential.inv_cdf(source.read::<f64>());
+        draws.push(cumulative);
+    }
+    cumulative += exponential.inv_cdf(source.read::<f64>());
+    let total = cumulative;
+    for x in draws.iter_mut() {
+        *x /= total;
+    }
+    draws
+}
+
+/// The `k`th smallest (0-indexed) of `n` independent draws from a
+/// distribution.
+///
+/// Sampling maps a single draw from `sorted_uniforms` through the
+/// distribution's `inv_cdf`, which is much faster for large `n` than
+/// drawing `n` samples and sorting them.
+pub struct OrderStatistic<D> {
+    distribution: D,
+    n: usize,
+    k: usize,
+}
+
+impl<D> OrderStatistic<D> {
+    /// Create an adapter for the `k`th smallest (0-indexed) of `n` draws
+    /// from `distribution`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k >= n`.
+    #[inline]
+    pub fn new(distribution: D, n: usize, k: usize) -> OrderStatistic<D> {
+        debug_assert!(k < n, "OrderStatistic::new() is called with k >= n");
+        OrderStatistic { distribution: distribution, n: n, k: k }
+    }
+}
+
+impl<D: Cdf> Sampleable for OrderStatistic<D> {
+    type Value = D::Value;
+
+    #[inline]
+    fn sample<S: Source>(&self, source: &mut S) -> D::Value {
+        let uniforms = sorted_uniforms(self.n, source);
+        self.distribution.inv_cdf(uniforms[self.k])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use random::default;
+
+    use distributions::{Exponential, Sampleable};
+    use super::{sorted_uniforms, OrderStatistic};
+
+    #[test]
+    fn sorted_uniforms_are_ascending() {
+        let mut source = default(42);
+        let draws = sorted_uniforms(10, &mut source);
+        for pair in draws.windows(2) {
+            assert!(pair[0] <= pair[1]);
+        }
+        for &x in &draws {
+            assert!(0.0 <= x && x <= 1.0);
+        }
+    }
+
+    #[test]
+    fn order_statistic_is_in_support() {
+        let mut source = default(42);
+        let stat = OrderStatistic::new(Exponential::new(1.0), 10, 9);
+        let x = stat.sample(&mut source);
+        assert!(x >= 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_k() {
+        OrderStatistic::new(Exponential::new(1.0), 5, 5);
+    }
+}
@@ -0,0 +1,19 @@
+//! Parameter (de)serialization and maximum-likelihood fitting.
+
+/// A distribution whose free parameters can be read out as a flat vector,
+/// reconstructed from one, and estimated from observed data.
+pub trait Parameterized: Sized {
+    /// The type of a single observation used by `fit`.
+    type Value;
+
+    /// Return the free parameters as a flat vector.
+    fn params(&self) -> Vec<f64>;
+
+    /// Reconstruct a distribution from its free parameters, in the order
+    /// returned by `params`.
+    fn from_params(params: &[f64]) -> Self;
+
+    /// Estimate parameters from `data`, by maximum likelihood unless the
+    /// implementation documents another estimator.
+    fn fit(data: &[Self::Value]) -> Self;
+}
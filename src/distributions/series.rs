@@ -0,0 +1,100 @@
+//! Acceleration of slowly converging series.
+
+const TOLERANCE: f64 = 1e-12;
+
+/// A generous cap on the number of terms to sum before giving up on
+/// convergence. Bounds the runtime for a series whose consecutive partial
+/// sums never satisfy the Aitken acceleration (e.g. a non-convergent or
+/// arithmetic-tailed series), where this would otherwise loop forever.
+const MAX_TERMS: usize = 10_000;
+
+/// Sum a slowly converging, non-negative series using Aitken's
+/// delta-squared process.
+///
+/// `term(n)` supplies the `n`th term (`n` starting at `0`). This forms
+/// the partial sums `S_n` and, once three consecutive partials `S_n,
+/// S_{n+1}, S_{n+2}` are available, the accelerated estimate
+///
+/// ```text
+/// S'_n = S_{n+2} - (S_{n+2} - S_{n+1})^2 / ((S_{n+2} - S_{n+1}) - (S_{n+1} - S_n))
+/// ```
+///
+/// iterating until successive `S'` differ by less than a tolerance, at
+/// which point the latest `S'` is returned. If the denominator
+/// underflows, the latest raw partial sum is used in its place instead of
+/// dividing by (near) zero. This converges in far fewer terms than naive
+/// summation for geometric-tailed series, such as the entropy or tail
+/// probabilities of discrete distributions. Only the last three partial
+/// sums are ever needed, so they're kept in a fixed-size window rather
+/// than an ever-growing buffer; if `MAX_TERMS` terms are summed without
+/// the estimate settling, the latest accelerated estimate is returned
+/// rather than looping forever.
+pub fn accelerate<F>(mut term: F) -> f64 where F: FnMut(usize) -> f64 {
+    let mut sum = 0.0;
+    let mut window = [0.0; 3];
+    let mut filled = 0;
+    let mut previous: Option<f64> = None;
+    let mut accelerated = 0.0;
+
+    for n in 0..MAX_TERMS {
+        sum += term(n);
+        if filled < 3 {
+            window[filled] = sum;
+            filled += 1;
+        } else {
+            window[0] = window[1];
+            window[1] = window[2];
+            window[2] = sum;
+        }
+
+        if filled < 3 {
+            continue;
+        }
+
+        let (s0, s1, s2) = (window[0], window[1], window[2]);
+        let d1 = s1 - s0;
+        let d2 = s2 - s1;
+        let denominator = d2 - d1;
+
+        accelerated = if denominator.abs() < ::std::f64::EPSILON {
+            s2
+        } else {
+            s2 - d2 * d2 / denominator
+        };
+
+        if let Some(previous) = previous {
+            if (accelerated - previous).abs() < TOLERANCE {
+                return accelerated;
+            }
+        }
+        previous = Some(accelerated);
+    }
+
+    accelerated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::accelerate;
+
+    #[test]
+    fn geometric_series() {
+        // sum_{n=0}^inf 0.5^n == 2
+        let sum = accelerate(|n| 0.5f64.powi(n as i32));
+        assert!((sum - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn single_term_series() {
+        let sum = accelerate(|n| if n == 0 { 1.0 } else { 0.0 });
+        assert!((sum - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn non_convergent_series_terminates() {
+        // Every consecutive difference is 1.0, so the estimate never
+        // settles; this must still return promptly instead of hanging.
+        let sum = accelerate(|_| 1.0);
+        assert!(sum.is_finite());
+    }
+}
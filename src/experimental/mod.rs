@@ -0,0 +1,10 @@
+//! Experimental distributions.
+//!
+//! These build on the stable distributions in `distributions` but their
+//! API may still change, typically because they implement only some of the
+//! narrower traits (`HasDensity`, `Sampleable`, `Cdf`, `Moments`) rather
+//! than all of `Distribution`.
+
+pub use self::stick_breaking::{StickBreaking, StickSequence};
+
+mod stick_breaking;
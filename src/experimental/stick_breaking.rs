@@ -0,0 +1,149 @@
+use random::Source;
+
+use distributions::{Beta, HasDensity, Sampleable};
+
+/// A stick-breaking (GEM) distribution over the atoms `0, 1, 2, ...`.
+///
+/// A draw proceeds by repeatedly breaking a unit-length stick: sampling
+/// `v_k ~ Beta(1, alpha)` and assigning weight `w_k = v_k *
+/// prod_{j<k}(1 - v_j)` to atom `k`. This is the mixing distribution
+/// behind a Dirichlet process, so it only supports a density (`pmf`) and
+/// sampling, not the moment or `Cdf` machinery of `Distribution`.
+#[derive(Clone, Copy)]
+pub struct StickBreaking {
+    /// The concentration parameter.
+    pub alpha: f64,
+}
+
+impl StickBreaking {
+    /// Create a stick-breaking distribution with concentration `alpha`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha <= 0`.
+    #[inline]
+    pub fn new(alpha: f64) -> StickBreaking {
+        debug_assert!(alpha > 0.0, "StickBreaking::new() is called with alpha <= 0");
+        StickBreaking { alpha: alpha }
+    }
+
+    /// Compute the probability mass assigned to atom `k`.
+    ///
+    /// Since `v_k ~ Beta(1, alpha)` independently of the break index,
+    /// `E[w_k] = E[v] * E[1 - v]^k`, which makes the marginal weights
+    /// geometric with success probability `1 / (1 + alpha)`.
+    #[inline]
+    pub fn pmf(&self, k: usize) -> f64 {
+        self.ln_pmf(k).exp()
+    }
+
+    /// Compute the natural logarithm of `pmf`.
+    #[inline]
+    pub fn ln_pmf(&self, k: usize) -> f64 {
+        let q = self.alpha / (1.0 + self.alpha);
+        k as f64 * q.ln() - (1.0 + self.alpha).ln()
+    }
+
+    /// Create a fresh, lazily-extended sequence of stick fragments for
+    /// repeated sampling without redrawing already-broken pieces.
+    #[inline]
+    pub fn to_sequence(&self) -> StickSequence {
+        StickSequence::new(self.alpha)
+    }
+}
+
+impl HasDensity for StickBreaking {
+    type Value = usize;
+
+    #[inline]
+    fn pdf(&self, k: usize) -> f64 { self.pmf(k) }
+
+    #[inline]
+    fn ln_pdf(&self, k: usize) -> f64 { self.ln_pmf(k) }
+}
+
+impl Sampleable for StickBreaking {
+    type Value = usize;
+
+    #[inline]
+    fn sample<S: Source>(&self, source: &mut S) -> usize {
+        self.to_sequence().sample(source)
+    }
+}
+
+/// A stick-breaking sequence whose fragments are broken off and cached
+/// lazily, so that the `Beta(1, alpha)` draws needed to reach atom `k`
+/// are never repeated across samples from the same sequence.
+pub struct StickSequence {
+    alpha: f64,
+    breaks: Vec<f64>,
+    remaining: f64,
+}
+
+impl StickSequence {
+    /// Create an empty sequence for a stick-breaking process with
+    /// concentration `alpha`.
+    #[inline]
+    pub fn new(alpha: f64) -> StickSequence {
+        StickSequence { alpha: alpha, breaks: Vec::new(), remaining: 1.0 }
+    }
+
+    /// Break off and cache one more fragment, returning its weight.
+    fn extend<S: Source>(&mut self, source: &mut S) -> f64 {
+        let v = Beta::new(1.0, self.alpha, 0.0, 1.0).sample(source);
+        let weight = v * self.remaining;
+        self.remaining -= weight;
+        self.breaks.push(weight);
+        weight
+    }
+
+    /// Return the number of fragments broken off and cached so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.breaks.len()
+    }
+
+    /// Draw a uniform `u` and walk the stick, breaking new fragments as
+    /// needed until the cumulative weight exceeds `u`, returning the atom
+    /// it lands on.
+    pub fn sample<S: Source>(&mut self, source: &mut S) -> usize {
+        let u = source.read::<f64>();
+        let mut cumulative = 0.0;
+        let mut k = 0;
+        loop {
+            if k == self.breaks.len() {
+                self.extend(source);
+            }
+            cumulative += self.breaks[k];
+            if cumulative > u { return k; }
+            k += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use distributions::HasDensity;
+    use super::StickBreaking;
+
+    #[test]
+    #[should_panic]
+    fn invalid_alpha() {
+        StickBreaking::new(0.0);
+    }
+
+    #[test]
+    fn pmf_decreases_with_atom_index() {
+        let d = StickBreaking::new(1.0);
+        assert!(d.pmf(0) > d.pmf(1));
+        assert!(d.pmf(1) > d.pmf(2));
+    }
+
+    #[test]
+    fn ln_pmf_matches_pmf() {
+        let d = StickBreaking::new(2.0);
+        for k in 0..5 {
+            assert!((d.ln_pdf(k) - d.pdf(k).ln()).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Probability distributions and sampling.
+
+extern crate random;
+
+pub use distributions::{Distribution, Parameterized};
+
+pub mod distributions;
+pub mod experimental;